@@ -31,6 +31,96 @@ pub trait ControlEntry:
 pub trait Control: ControlEntry {}
 pub trait Property: ControlEntry {}
 
+mod private {
+    pub trait Sealed {}
+}
+
+/// Marker for the read/write capability of a [`ControlList`], carried purely as a zero-sized type parameter.
+///
+/// This trait is sealed: [`Readable`] and [`Writable`] are the only implementors.
+pub trait ControlListState: private::Sealed {}
+
+/// Marks a [`ControlList`] as read-only, e.g. request metadata returned by a completed [`Request`](crate::request::Request).
+pub enum Readable {}
+
+/// Marks a [`ControlList`] as writable, e.g. the controls attached to a [`Request`](crate::request::Request) before it is queued.
+pub enum Writable {}
+
+impl private::Sealed for Readable {}
+impl private::Sealed for Writable {}
+impl ControlListState for Readable {}
+impl ControlListState for Writable {}
+
+/// A list of [`Control`]s or [`Property`]s backed by an opaque `libcamera::ControlList`.
+///
+/// [`ControlList`] and [`PropertyList`] both wrap the exact same underlying libcamera type and share the iteration
+/// behavior captured here. Looking up a typed entry is **not** part of this trait: [`ControlList`] only makes sense
+/// with [`Control`] types and [`PropertyList`] only with [`Property`] types, so that lookup lives on
+/// [`ControlListLikeControls`]/[`PropertyListLike`] instead, keeping the two kinds of entry from being mixed up at a
+/// call site.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ControlListLike: private::Sealed {
+    #[doc(hidden)]
+    fn ptr(&self) -> *const libcamera_control_list_t;
+
+    /// Returns an iterator over the raw `(id, value)` pairs held by this list.
+    fn iter(&self) -> ControlListRefIterator<'_> {
+        ControlListRefIterator {
+            it: NonNull::new(unsafe { libcamera_control_list_iter(self.ptr().cast_mut()) }).unwrap(),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+/// Extends [`ControlListLike`] with typed lookup of [`Control`] entries, implemented by [`ControlList`].
+pub trait ControlListLikeControls: ControlListLike {
+    /// Gets the value of a control in the list.
+    fn get<C: Control>(&self) -> Result<C, ControlError> {
+        let val_ptr = NonNull::new(unsafe { libcamera_control_list_get(self.ptr().cast_mut(), C::ID as _).cast_mut() })
+            .ok_or(ControlError::NotFound(C::ID))?;
+
+        let val = unsafe { ControlValue::read(val_ptr) }?;
+        Ok(C::try_from(val)?)
+    }
+}
+
+/// Extends [`ControlListLike`] with typed lookup of [`Property`] entries, implemented by [`PropertyList`].
+pub trait PropertyListLike: ControlListLike {
+    /// Gets the value of a property in the list.
+    fn get<P: Property>(&self) -> Result<P, ControlError> {
+        let val_ptr = NonNull::new(unsafe { libcamera_control_list_get(self.ptr().cast_mut(), P::ID as _).cast_mut() })
+            .ok_or(ControlError::NotFound(P::ID))?;
+
+        let val = unsafe { ControlValue::read(val_ptr) }?;
+        Ok(P::try_from(val)?)
+    }
+}
+
+/// Extends [`ControlListLikeControls`] with mutation, available only for [`ControlList<Writable>`].
+///
+/// Metadata returned by completed requests and property lists read from a camera are conceptually read-only;
+/// keeping `set` on a separate, sealed trait means calling it on a [`ControlList<Readable>`] or [`PropertyList`]
+/// is a compile error rather than a silently-ignored no-op.
+pub trait ControlListLikeMut: ControlListLikeControls {
+    /// Sets the value of a control in the list.
+    ///
+    /// This can fail if control is not supported by the camera, but due to libcamera API limitations an error will not
+    /// be returned. Use [ControlListLikeControls::get] if you need to ensure that value was set.
+    fn set<C: Control>(&mut self, val: C) -> Result<(), ControlError> {
+        let ctrl_val: ControlValue = val.into();
+
+        unsafe {
+            let val_ptr = NonNull::new(libcamera_control_value_create()).unwrap();
+            ctrl_val.write(val_ptr);
+            libcamera_control_list_set(self.ptr().cast_mut(), C::ID as _, val_ptr.as_ptr());
+            libcamera_control_value_destroy(val_ptr.as_ptr());
+        }
+
+        Ok(())
+    }
+}
+
 /// Dynamic Control, which does not have strong typing.
 pub trait DynControlEntry: core::fmt::Debug {
     fn id(&self) -> u32;
@@ -136,12 +226,99 @@ impl ControlInfoMap {
             unsafe { Some(ControlInfo::from_ptr(NonNull::new_unchecked(info_ptr as *mut _))) }
         }
     }
+
+    /// Returns the number of controls supported by this map.
+    pub fn len(&self) -> usize {
+        unsafe { libcamera_control_info_map_size(self.ptr()) }
+    }
+
+    /// Returns `true` if this map supports no controls.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over every control supported by this map, along with its [`ControlInfo`] (min/max/default
+    /// value and allowed values).
+    ///
+    /// This is the discovery step needed to build a capabilities UI, or to configure a request without probing
+    /// every known [`ControlId`] one by one.
+    pub fn iter(&self) -> ControlInfoMapRefIterator<'_> {
+        ControlInfoMapRefIterator {
+            it: NonNull::new(unsafe { libcamera_control_info_map_iter(self.ptr().cast_mut()) }).unwrap(),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+/// A control id encountered while iterating a [`ControlInfoMap`]: either a known [`ControlId`] variant, or the raw
+/// numeric id for a control this crate doesn't have a variant for yet (e.g. vendor-specific controls).
+///
+/// [`ControlInfoMapRefIterator`] yields one of these per entry rather than skipping unrecognized ids, so its item
+/// count always matches [`ControlInfoMap::len`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlIdOrRaw {
+    Known(ControlId),
+    Raw(u32),
+}
+
+impl From<u32> for ControlIdOrRaw {
+    fn from(id: u32) -> Self {
+        match ControlId::try_from(id) {
+            Ok(id) => Self::Known(id),
+            Err(_) => Self::Raw(id),
+        }
+    }
 }
 
+impl<'d> IntoIterator for &'d ControlInfoMap {
+    type Item = (ControlIdOrRaw, &'d ControlInfo);
+
+    type IntoIter = ControlInfoMapRefIterator<'d>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct ControlInfoMapRefIterator<'d> {
+    it: NonNull<libcamera_control_info_map_iter_t>,
+    _phantom: PhantomData<&'d ()>,
+}
+
+impl<'d> Iterator for ControlInfoMapRefIterator<'d> {
+    type Item = (ControlIdOrRaw, &'d ControlInfo);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if unsafe { libcamera_control_info_map_iter_end(self.it.as_ptr()) } {
+            return None;
+        }
+
+        let id = unsafe { libcamera_control_info_map_iter_id(self.it.as_ptr()) };
+        let info_ptr =
+            NonNull::new(unsafe { libcamera_control_info_map_iter_value(self.it.as_ptr()).cast_mut() }).unwrap();
+        let info = unsafe { ControlInfo::from_ptr(info_ptr) };
+
+        unsafe { libcamera_control_info_map_iter_next(self.it.as_ptr()) };
+
+        Some((ControlIdOrRaw::from(id), info))
+    }
+}
+
+impl Drop for ControlInfoMapRefIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { libcamera_control_info_map_iter_destroy(self.it.as_ptr()) }
+    }
+}
+
+/// A list of [`Control`] values, type-stated by whether it can be [`set`](ControlListLikeMut::set) ([`Writable`])
+/// or only [`get`](ControlListLikeControls::get) ([`Readable`]).
+///
+/// Freshly-constructed lists ([`ControlList::new`]) and FFI constructors feeding a request's controls produce
+/// [`ControlList<Writable>`]; metadata lists returned by a completed request come back as [`ControlList<Readable>`].
 #[repr(transparent)]
-pub struct ControlList(libcamera_control_list_t);
+pub struct ControlList<State: ControlListState = Writable>(libcamera_control_list_t, PhantomData<State>);
 
-impl UniquePtrTarget for ControlList {
+impl<State: ControlListState> UniquePtrTarget for ControlList<State> {
     unsafe fn ptr_new() -> *mut Self {
         libcamera_control_list_create() as *mut Self
     }
@@ -151,11 +328,92 @@ impl UniquePtrTarget for ControlList {
     }
 }
 
-impl ControlList {
+impl ControlList<Writable> {
     pub fn new() -> UniquePtr<Self> {
         UniquePtr::new()
     }
 
+    /// Downgrades a writable control list into a read-only [`ControlList<Readable>`], so [`ControlListLikeMut::set`]
+    /// can no longer be called on it.
+    pub fn into_readable(this: UniquePtr<Self>) -> UniquePtr<ControlList<Readable>> {
+        // Safety: `ControlList<Writable>` and `ControlList<Readable>` are both `#[repr(transparent)]` wrappers
+        // around the same `libcamera_control_list_t`, differing only in a zero-sized `PhantomData<State>`, so
+        // they share layout and reinterpreting the owning pointer is sound.
+        unsafe { std::mem::transmute::<UniquePtr<ControlList<Writable>>, UniquePtr<ControlList<Readable>>>(this) }
+    }
+
+    /// Sets the value of a control chosen at runtime, without requiring its concrete [`Control`] type at compile
+    /// time. See [`ControlList::get_dyn`] for the read-side counterpart.
+    ///
+    /// This can fail if control is not supported by the camera, but due to libcamera API limitations an error will
+    /// not be returned. Use [`ControlList::get_dyn`] if you need to ensure that value was set.
+    pub fn set_dyn(&mut self, id: ControlId, value: ControlValue) -> Result<(), ControlError> {
+        self.set_raw(id as u32, value);
+        Ok(())
+    }
+
+    /// Sets the value of a control from a type-erased [`DynControlEntry`], e.g. one produced by
+    /// [`controls::make_dyn`].
+    pub fn set_dyn_entry(&mut self, entry: Box<dyn DynControlEntry>) -> Result<(), ControlError> {
+        let id = ControlId::try_from(entry.id()).map_err(|_| ControlError::NotFound(entry.id()))?;
+        self.set_dyn(id, entry.value())
+    }
+
+    /// Sets the value of a control by its raw numeric id, bypassing [`ControlId`] entirely.
+    ///
+    /// This exists for ids that don't (yet) have a [`ControlId`] variant, e.g. vendor-specific controls.
+    pub(crate) fn set_raw(&mut self, id: u32, value: ControlValue) {
+        unsafe {
+            let val_ptr = NonNull::new(libcamera_control_value_create()).unwrap();
+            value.write(val_ptr);
+            libcamera_control_list_set(self.ptr().cast_mut(), id, val_ptr.as_ptr());
+            libcamera_control_value_destroy(val_ptr.as_ptr());
+        }
+    }
+
+    /// Merges entries from `other` into this list.
+    ///
+    /// If `overwrite` is `true`, entries in `other` replace existing entries in `self` that share the same id. If
+    /// `false`, existing entries in `self` take precedence and are left untouched. This mirrors the `overwrite`
+    /// behavior of libcamera's own `ControlList::merge`, but is implemented as a plain loop over `other` and
+    /// `set_raw` rather than calling into libcamera, so it won't reproduce any libcamera-side merge behavior beyond
+    /// that (e.g. id-set validation).
+    ///
+    /// This makes it easy to compose a base preset with per-frame overrides: load a saved list (see the `serde`
+    /// feature), then merge runtime adjustments on top.
+    pub fn merge<State: ControlListState>(&mut self, other: &ControlList<State>, overwrite: bool) {
+        for (id, value) in other {
+            if overwrite || self.get_raw(id).is_err() {
+                self.set_raw(id, value);
+            }
+        }
+    }
+}
+
+impl Extend<(ControlId, ControlValue)> for ControlList<Writable> {
+    fn extend<I: IntoIterator<Item = (ControlId, ControlValue)>>(&mut self, iter: I) {
+        for (id, value) in iter {
+            let _ = self.set_dyn(id, value);
+        }
+    }
+}
+
+impl FromIterator<(ControlId, ControlValue)> for UniquePtr<ControlList<Writable>> {
+    fn from_iter<I: IntoIterator<Item = (ControlId, ControlValue)>>(iter: I) -> Self {
+        let mut list = ControlList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<State: ControlListState> ControlList<State> {
+    /// Reinterprets a raw `libcamera_control_list_t*` as a `ControlList<State>`.
+    ///
+    /// `State` is not checked against the pointer in any way, so the caller picks it: use
+    /// [`Writable`] for a list the caller is expected to populate before handing it back to libcamera (e.g. the
+    /// controls attached to a request before it's queued), and [`Readable`] for a list libcamera owns and only
+    /// hands out for reading (e.g. metadata on a completed request). That wiring lives with whichever FFI callback
+    /// produces the pointer, outside of this file.
     pub(crate) unsafe fn from_ptr<'a>(ptr: NonNull<libcamera_control_list_t>) -> &'a mut Self {
         // Safety: we can cast it because of `#[repr(transparent)]`
         &mut *(ptr.as_ptr() as *mut Self)
@@ -166,46 +424,48 @@ impl ControlList {
         &self.0 as *const libcamera_control_list_t
     }
 
-    pub fn get<C: Control>(&self) -> Result<C, ControlError> {
-        let val_ptr = NonNull::new(unsafe { libcamera_control_list_get(self.ptr().cast_mut(), C::ID as _).cast_mut() })
-            .ok_or(ControlError::NotFound(C::ID))?;
-
-        let val = unsafe { ControlValue::read(val_ptr) }?;
-        Ok(C::try_from(val)?)
+    /// Gets the value of a control chosen at runtime, without requiring its concrete [`Control`] type at compile
+    /// time.
+    ///
+    /// This is useful for applications that pick which control to read from a config file or a UI control picker
+    /// rather than a fixed set of [`Control`] types known up-front. Use [`ControlListLikeControls::get`] instead when the
+    /// control is statically known.
+    pub fn get_dyn(&self, id: ControlId) -> Result<ControlValue, ControlError> {
+        self.get_raw(id as u32)
     }
 
-    /// Sets control value.
-    ///
-    /// This can fail if control is not supported by the camera, but due to libcamera API limitations an error will not
-    /// be returned. Use [ControlList::get] if you need to ensure that value was set.
-    pub fn set<C: Control>(&mut self, val: C) -> Result<(), ControlError> {
-        let ctrl_val: ControlValue = val.into();
+    /// Gets the value of a control by its raw numeric id, bypassing [`ControlId`] entirely.
+    pub(crate) fn get_raw(&self, id: u32) -> Result<ControlValue, ControlError> {
+        let val_ptr = NonNull::new(unsafe { libcamera_control_list_get(self.ptr().cast_mut(), id).cast_mut() })
+            .ok_or(ControlError::NotFound(id))?;
 
-        unsafe {
-            let val_ptr = NonNull::new(libcamera_control_value_create()).unwrap();
-            ctrl_val.write(val_ptr);
-            libcamera_control_list_set(self.ptr().cast_mut(), C::ID as _, val_ptr.as_ptr());
-            libcamera_control_value_destroy(val_ptr.as_ptr());
-        }
+        Ok(unsafe { ControlValue::read(val_ptr) }?)
+    }
+}
 
-        Ok(())
+impl<State: ControlListState> private::Sealed for ControlList<State> {}
+
+impl<State: ControlListState> ControlListLike for ControlList<State> {
+    fn ptr(&self) -> *const libcamera_control_list_t {
+        ControlList::ptr(self)
     }
 }
 
-impl<'d> IntoIterator for &'d ControlList {
+impl<State: ControlListState> ControlListLikeControls for ControlList<State> {}
+
+impl ControlListLikeMut for ControlList<Writable> {}
+
+impl<'d, State: ControlListState> IntoIterator for &'d ControlList<State> {
     type Item = (u32, ControlValue);
 
     type IntoIter = ControlListRefIterator<'d>;
 
     fn into_iter(self) -> Self::IntoIter {
-        ControlListRefIterator {
-            it: NonNull::new(unsafe { libcamera_control_list_iter(self.ptr().cast_mut()) }).unwrap(),
-            _phantom: Default::default(),
-        }
+        self.iter()
     }
 }
 
-impl core::fmt::Debug for ControlList {
+impl<State: ControlListState> core::fmt::Debug for ControlList<State> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut map = f.debug_map();
         for (id, val) in self.into_iter() {
@@ -236,43 +496,25 @@ impl PropertyList {
         // Safety: we can cast it because of `#[repr(transparent)]`
         &self.0 as *const libcamera_control_list_t
     }
+}
 
-    pub fn get<C: Property>(&self) -> Result<C, ControlError> {
-        let val_ptr = NonNull::new(unsafe { libcamera_control_list_get(self.ptr().cast_mut(), C::ID as _).cast_mut() })
-            .ok_or(ControlError::NotFound(C::ID))?;
+impl private::Sealed for PropertyList {}
 
-        let val = unsafe { ControlValue::read(val_ptr) }?;
-        Ok(C::try_from(val)?)
-    }
-
-    /// Sets property value.
-    ///
-    /// This can fail if property is not supported by the camera, but due to libcamera API limitations an error will not
-    /// be returned. Use [PropertyList::get] if you need to ensure that value was set.
-    pub fn set<C: Property>(&mut self, val: C) -> Result<(), ControlError> {
-        let ctrl_val: ControlValue = val.into();
-
-        unsafe {
-            let val_ptr = NonNull::new(libcamera_control_value_create()).unwrap();
-            ctrl_val.write(val_ptr);
-            libcamera_control_list_set(self.ptr().cast_mut(), C::ID as _, val_ptr.as_ptr());
-            libcamera_control_value_destroy(val_ptr.as_ptr());
-        }
-
-        Ok(())
+impl ControlListLike for PropertyList {
+    fn ptr(&self) -> *const libcamera_control_list_t {
+        PropertyList::ptr(self)
     }
 }
 
+impl PropertyListLike for PropertyList {}
+
 impl<'d> IntoIterator for &'d PropertyList {
     type Item = (u32, ControlValue);
 
     type IntoIter = ControlListRefIterator<'d>;
 
     fn into_iter(self) -> Self::IntoIter {
-        ControlListRefIterator {
-            it: NonNull::new(unsafe { libcamera_control_list_iter(self.ptr().cast_mut()) }).unwrap(),
-            _phantom: Default::default(),
-        }
+        self.iter()
     }
 }
 
@@ -323,3 +565,77 @@ impl Drop for ControlListRefIterator<'_> {
         unsafe { libcamera_control_list_iter_destroy(self.it.as_ptr()) }
     }
 }
+
+/// `serde` support for persisting a [`ControlList`] snapshot (e.g. a saved exposure/gain/white-balance preset) to a
+/// self-describing format like JSON or TOML and loading it back.
+///
+/// Entries are keyed by their raw numeric id rather than by control name: resolving a name back to a [`ControlId`]
+/// on deserialize would need `FromStr`, which this crate doesn't implement, and serializing by name while
+/// deserializing only by number would silently break the round trip for every *known* control. Keying by number on
+/// both sides keeps `Serialize`/`Deserialize` consistent, at the cost of presets being less readable than a
+/// name-keyed format would be. [`ControlValue`] is serialized/deserialized via its own (de)serialize impls, which
+/// live outside this file.
+///
+/// [`PropertyList`] only gets `Serialize`: it has no writable constructor in this crate (no `UniquePtrTarget` impl,
+/// no `new`), so there's nothing for a `Deserialize` impl to build into.
+#[cfg(feature = "serde")]
+mod serde_support {
+    use serde::{
+        de::{Error as DeError, MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::*;
+
+    impl<State: ControlListState> Serialize for ControlList<State> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_control_list_like(self, serializer)
+        }
+    }
+
+    impl Serialize for PropertyList {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_control_list_like(self, serializer)
+        }
+    }
+
+    fn serialize_control_list_like<L, S>(list: &L, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        for<'d> &'d L: IntoIterator<Item = (u32, ControlValue)>,
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        for (id, val) in list {
+            map.serialize_entry(&id.to_string(), &val)?;
+        }
+        map.end()
+    }
+
+    impl<'de> Deserialize<'de> for UniquePtr<ControlList<Writable>> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct ControlListVisitor;
+
+            impl<'de> Visitor<'de> for ControlListVisitor {
+                type Value = UniquePtr<ControlList<Writable>>;
+
+                fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("a map of numeric control id to control value")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                    let mut list = ControlList::new();
+                    while let Some((key, value)) = access.next_entry::<String, ControlValue>()? {
+                        let raw = key
+                            .parse::<u32>()
+                            .map_err(|_| A::Error::custom(format!("expected a numeric control id, got `{key}`")))?;
+                        list.set_raw(raw, value);
+                    }
+                    Ok(list)
+                }
+            }
+
+            deserializer.deserialize_map(ControlListVisitor)
+        }
+    }
+}